@@ -271,6 +271,20 @@ pub trait Provider<E = anyhow::Error>: Sync {
         async { Ok(()) }
     }
 
+    /// Maximum number of links that may be received concurrently at provider startup
+    ///
+    /// When the provider starts, it is given every link that already exists on the lattice. By
+    /// default those links are passed to [`Provider::receive_link_config_as_source`] and
+    /// [`Provider::receive_link_config_as_target`] one at a time, in the order provided by the host.
+    ///
+    /// Providers whose link handling is safe to run concurrently may return a larger value to become
+    /// ready faster when there are many links. Those methods may then be called concurrently during
+    /// startup (including for links between the same source and target), and the order in which
+    /// startup links are received is unspecified.
+    fn max_concurrent_startup_links(&self) -> usize {
+        1
+    }
+
     /// Receive and handle a link that has been established on the lattice where this provider is the source.
     ///
     /// Implement this when your provider needs to call other components.
@@ -25,8 +25,8 @@ use wasmcloud_core::nats::convert_header_map_to_hashmap;
 use wasmcloud_core::rpc::{health_subject, link_del_subject, link_put_subject, shutdown_subject};
 use wasmcloud_core::secrets::SecretValue;
 use wasmcloud_core::{
-    provider_config_update_subject, HealthCheckRequest, HealthCheckResponse, HostData,
-    InterfaceLinkDefinition, LatticeTarget,
+    provider_config_update_subject, ComponentId, HealthCheckRequest, HealthCheckResponse, HostData,
+    InterfaceLinkDefinition, LatticeTarget, LinkName,
 };
 
 #[cfg(feature = "otel")]
@@ -543,12 +543,16 @@ async fn init_provider(name: &str) -> ProviderInitResult<ProviderInitState> {
     })
 }
 
-/// Appropriately receive a link (depending on if it's source/target) for a provider
-async fn receive_link_for_provider<P>(
+/// Hand a link to the provider (depending on if it's source/target), returning whether the
+/// provider accepted it.
+///
+/// A provider rejecting the link is logged here; an error is returned only if the link itself
+/// could not be handed to the provider.
+async fn deliver_link_to_provider<P>(
     provider: &P,
     connection: &ProviderConnection,
-    ld: InterfaceLinkDefinition,
-) -> Result<()>
+    ld: &InterfaceLinkDefinition,
+) -> Result<bool>
 where
     P: Provider,
 {
@@ -585,14 +589,97 @@ where
     } else {
         bail!("received link put where provider was neither source nor target");
     } {
-        Ok(()) => connection.put_link(ld).await,
+        Ok(()) => Ok(true),
         Err(e) => {
-            warn!(error = %e, "receiving link failed");
+            warn!(
+                error = ?e,
+                source_id = &ld.source_id,
+                target = &ld.target,
+                link_name = &ld.name,
+                "receiving link failed"
+            );
+            Ok(false)
         }
-    };
+    }
+}
+
+/// Appropriately receive a link (depending on if it's source/target) for a provider
+async fn receive_link_for_provider<P>(
+    provider: &P,
+    connection: &ProviderConnection,
+    ld: InterfaceLinkDefinition,
+) -> Result<()>
+where
+    P: Provider,
+{
+    if deliver_link_to_provider(provider, connection, &ld).await? {
+        connection.put_link(ld).await;
+    }
     Ok(())
 }
 
+/// Source ID, target and name of a link that failed to initialize at provider startup
+type FailedLink = (ComponentId, LatticeTarget, LinkName);
+
+/// Receive a link that already existed when the provider started, returning the link's
+/// [`FailedLink`] identity if the provider rejected it or it could not be handed to the provider
+async fn receive_startup_link<P>(
+    provider: &P,
+    connection: &ProviderConnection,
+    mut ld: InterfaceLinkDefinition,
+) -> core::result::Result<(), FailedLink>
+where
+    P: Provider,
+{
+    match deliver_link_to_provider(provider, connection, &ld).await {
+        Ok(true) => {
+            connection.put_link(ld).await;
+            return Ok(());
+        }
+        Ok(false) => {}
+        Err(e) => {
+            error!(
+                error = ?e,
+                source_id = &ld.source_id,
+                target = &ld.target,
+                link_name = &ld.name,
+                "failed to initialize link during provider startup",
+            );
+        }
+    }
+    Err((
+        std::mem::take(&mut ld.source_id),
+        std::mem::take(&mut ld.target),
+        std::mem::take(&mut ld.name),
+    ))
+}
+
+/// Runs `receive` for each of the given links, with at most `max_concurrent` in flight at once.
+///
+/// With a `max_concurrent` of 1 links are received one at a time in the order given. Every link is
+/// processed regardless of failures, and the total number of links is returned along with the
+/// errors of those that failed.
+async fn receive_startup_links<T, E, F, Fut>(
+    links: impl IntoIterator<Item = T>,
+    max_concurrent: usize,
+    receive: F,
+) -> (usize, Vec<E>)
+where
+    F: FnMut(T) -> Fut,
+    Fut: Future<Output = core::result::Result<(), E>>,
+{
+    stream::iter(links)
+        .map(receive)
+        .buffer_unordered(max_concurrent.max(1))
+        .fold((0, Vec::new()), |(total, mut failed), res| async move {
+            if let Err(e) = res {
+                failed.push(e);
+            }
+            (total + 1, failed)
+        })
+        .await
+}
+
 /// Given a serialized and encrypted [`HashMap<String, SecretValue>`], decrypts the secrets and deserializes
 /// the inner bytes into a [`HashMap<String, SecretValue>`]. This can either fail due to a decryption error
 /// or a deserialization error.
@@ -823,14 +910,26 @@ pub async fn run_provider(
     })?;
     let connection = get_connection();
 
-    // Provide all links to the provider at startup to establish the initial state
-    for ld in link_definitions {
-        if let Err(e) = receive_link_for_provider(&provider, connection, ld).await {
-            error!(
-                error = %e,
-                "failed to initialize link during provider startup",
-            );
-        }
+    // Provide all links to the provider at startup to establish the initial state. Providers may
+    // opt into receiving these concurrently so they become ready quickly with many links.
+    let (total_links, failed_links) = receive_startup_links(
+        link_definitions,
+        provider.max_concurrent_startup_links(),
+        |ld| receive_startup_link(&provider, connection, ld),
+    )
+    .await;
+    if failed_links.is_empty() {
+        info!(
+            total_links,
+            "provider initialized all links received at startup"
+        );
+    } else {
+        warn!(
+            total_links,
+            failed = failed_links.len(),
+            ?failed_links,
+            "provider started with links that failed to initialize"
+        );
     }
 
     debug!(?friendly_name, "provider finished initialization");
@@ -1150,3 +1249,159 @@ impl ProviderConnection {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use core::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Mutex;
+
+    use super::*;
+
+    /// Provider that rejects links from any source whose ID starts with `reject`
+    struct RejectingProvider;
+
+    impl Provider for RejectingProvider {
+        async fn receive_link_config_as_target(&self, config: LinkConfig<'_>) -> Result<()> {
+            if config.source_id.starts_with("reject") {
+                bail!("link rejected by provider");
+            }
+            Ok(())
+        }
+    }
+
+    async fn test_connection() -> ProviderConnection {
+        // The connection is never used to send anything, so there doesn't need to be a server
+        // listening
+        let nats = async_nats::ConnectOptions::new()
+            .retry_on_initial_connect()
+            .connect(DEFAULT_NATS_ADDR)
+            .await
+            .expect("failed to create NATS client");
+        ProviderConnection::new(
+            Arc::new(nats),
+            "provider".into(),
+            "default".into(),
+            "host".into(),
+            HashMap::new(),
+            XKey::new(),
+            XKey::new(),
+        )
+        .expect("failed to create provider connection")
+    }
+
+    fn link_to_provider(source_id: &str) -> InterfaceLinkDefinition {
+        InterfaceLinkDefinition {
+            source_id: source_id.into(),
+            target: "provider".into(),
+            name: "default".into(),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn startup_links_bounded_concurrency() {
+        let in_flight = &AtomicUsize::new(0);
+        let max_in_flight = &AtomicUsize::new(0);
+        let (total, failed) = receive_startup_links(0..100, 4, |_| async move {
+            let current = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+            max_in_flight.fetch_max(current, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(5)).await;
+            in_flight.fetch_sub(1, Ordering::SeqCst);
+            Ok::<_, ()>(())
+        })
+        .await;
+        assert_eq!(total, 100);
+        assert!(failed.is_empty());
+        assert!(max_in_flight.load(Ordering::SeqCst) <= 4);
+        assert!(max_in_flight.load(Ordering::SeqCst) > 1);
+    }
+
+    #[tokio::test]
+    async fn startup_links_sequential_preserves_order() {
+        let processed = &Mutex::new(Vec::new());
+        let (total, failed) = receive_startup_links(0..20, 1, |i| async move {
+            tokio::time::sleep(Duration::from_millis(20 - i)).await;
+            processed.lock().unwrap().push(i);
+            Ok::<_, ()>(())
+        })
+        .await;
+        assert_eq!(total, 20);
+        assert!(failed.is_empty());
+        assert_eq!(*processed.lock().unwrap(), (0..20).collect::<Vec<_>>());
+    }
+
+    #[tokio::test]
+    async fn startup_links_collect_failures_and_process_all() {
+        let processed = &Mutex::new(Vec::new());
+        let (total, mut failed) = receive_startup_links(0..10, 3, |i| async move {
+            processed.lock().unwrap().push(i);
+            if i % 3 == 0 {
+                Err(i)
+            } else {
+                Ok(())
+            }
+        })
+        .await;
+        assert_eq!(total, 10);
+        failed.sort_unstable();
+        assert_eq!(failed, [0, 3, 6, 9]);
+        let mut processed = processed.lock().unwrap().clone();
+        processed.sort_unstable();
+        assert_eq!(processed, (0..10).collect::<Vec<_>>());
+    }
+
+    #[tokio::test]
+    async fn startup_links_reports_rejected_and_invalid_links() {
+        let provider = RejectingProvider;
+        let connection = test_connection().await;
+        let links = vec![
+            link_to_provider("component-a"),
+            link_to_provider("reject-b"),
+            InterfaceLinkDefinition {
+                source_id: "component-c".into(),
+                target: "other-provider".into(),
+                name: "default".into(),
+                ..Default::default()
+            },
+            InterfaceLinkDefinition {
+                target_secrets: Some(b"not encrypted".to_vec()),
+                ..link_to_provider("component-d")
+            },
+            link_to_provider("component-e"),
+        ];
+        let (total, failed) =
+            receive_startup_links(links, provider.max_concurrent_startup_links(), |ld| {
+                receive_startup_link(&provider, &connection, ld)
+            })
+            .await;
+
+        assert_eq!(total, 5);
+        assert_eq!(
+            failed,
+            [
+                ("reject-b", "provider", "default"),
+                ("component-c", "other-provider", "default"),
+                ("component-d", "provider", "default"),
+            ]
+            .map(|(source_id, target, name)| (
+                source_id.to_string(),
+                target.to_string(),
+                name.to_string()
+            ))
+        );
+        for (source_id, linked) in [
+            ("component-a", true),
+            ("reject-b", false),
+            ("component-d", false),
+            ("component-e", true),
+        ] {
+            assert_eq!(
+                connection
+                    .is_linked(source_id, "provider", "", "", "default")
+                    .await,
+                linked,
+                "unexpected link state for {source_id}"
+            );
+        }
+    }
+}